@@ -17,36 +17,735 @@ limitations under the License.
 //!
 //! A procedural macro library for allowing conversion from iterators to user-defined
 //! [`struct`](https://doc.rust-lang.org/1.58.1/std/keyword.struct.html)s.
-//! 
+//!
 //! This library does so by implementing a procedural macro, [`macro@iter_convertable`] for [`struct`](https://doc.rust-lang.org/1.58.1/std/keyword.struct.html) definitions that automatically implements [`structinator_traits::SpecifyCreatableStruct`]
-//! for the defined [`struct`](https://doc.rust-lang.org/1.58.1/std/keyword.struct.html). 
-//! 
+//! for the defined [`struct`](https://doc.rust-lang.org/1.58.1/std/keyword.struct.html).
+//!
 //! For more information about how [`macro@iter_convertable`] implements [`SpecifyCreatableStruct`](structinator_traits::SpecifyCreatableStruct), visit the macro's [documentation](macro@iter_convertable)
-//! 
+//!
 //! For more information about how an implementation of [`SpecifyCreatableStruct`](structinator_traits::SpecifyCreatableStruct) allows for easy conversion between [`Iterator`]s and [`struct`](https://doc.rust-lang.org/1.58.1/std/keyword.struct.html)s, visit the documentation of [`structinator_traits`]
 use syn;
-use quote::quote;
+use quote::{quote, format_ident};
 use proc_macro::TokenStream;
 
+///The arguments [`macro@iter_convertable`] accepts: the inner iterator type, followed by an optional comma-separated list of mode options.
+///
+/// Currently recognised options are `accumulate_errors` (see [`ErrorHandling::AccumulateErrors`]) and `positional` (see [`ValueLookup::Positional`]).
+struct IterConvertableArgs {
+    inner_iterator_type: syn::Type,
+    options: std::collections::HashSet<String>,
+}
+
+impl syn::parse::Parse for IterConvertableArgs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let inner_iterator_type: syn::Type = input.parse()?;
+        let mut options = std::collections::HashSet::new();
+        while input.peek(syn::Token![,]) {
+            input.parse::<syn::Token![,]>()?;
+            let option_ident: syn::Ident = input.parse()?;
+            options.insert(option_ident.to_string());
+        }
+        Ok(IterConvertableArgs { inner_iterator_type, options })
+    }
+}
+
+///Selects which failure-handling strategy [`build_create_struct_impl`] should emit for `create_struct`.
+enum ErrorHandling {
+    ///The default: panic on a failed conversion, and return a fixed [`&'static str`] if the iterator runs out early.
+    PanicOnFailure,
+    ///The opt-in `accumulate_errors` mode: collect every missing or unconvertible field into a [`Vec<StructError>`](structinator_traits::StructError) instead of panicking.
+    AccumulateErrors,
+}
+
+///Selects how [`build_create_struct_impl`] retrieves each field's value out of the iterator supplied to `create_struct`.
+enum ValueLookup {
+    ///The default: collect every value into a `HashMap<String,InnerIteratorType>` up front, then look each field up by its stringified name.
+    HashMap,
+    ///The opt-in `positional` mode: assume `seed_iterator` yields values in field-declaration order, and pull them off one at a time,
+    /// skipping the `HashMap` allocation and the string hashing/lookup per field entirely.
+    Positional,
+}
+
+///A field of the struct being processed, normalized so the rest of this crate doesn't need to care whether it came from a named,
+/// tuple, or unit struct.
+struct FieldDescriptor {
+    ///The key used to look the field's value up among the collected [`NamedField`](structinator_traits::NamedField)s: the field's
+    /// stringified name for named fields, or its stringified position (`"0"`, `"1"`, ...) for tuple fields.
+    key: String,
+    ///A valid identifier to bind the field's converted value to in generated code, even for tuple-struct fields (which have no identifier of their own).
+    binding_ident: syn::Ident,
+    ///How to read this field's current value off of `self`: `self.field_name` for named fields, `self.0`/`self.1`/... for tuple fields.
+    access: proc_macro2::TokenStream,
+    ///Set from a `#[structinator(default)]` or `#[structinator(default = expr)]` attribute on the field: the expression to fall back to
+    /// when the field's value is absent from the collected values, instead of treating that as a missing-field error.
+    default_expr: Option<syn::Expr>,
+    ty: syn::Type,
+}
+
+///Scans a field's attributes for `#[structinator(default)]` or `#[structinator(default = expr)]`, returning the fallback expression to
+/// use when the field's value is absent from the collected values: the given expression, or `Default::default()` if none was given.
+fn field_default_expr(field: &syn::Field) -> Option<syn::Expr> {
+    let mut default_expr = None;
+    for attr in &field.attrs {
+        if !attr.path().is_ident("structinator") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("default") {
+                default_expr = Some(if meta.input.peek(syn::Token![=]) {
+                    meta.value()?.parse()?
+                } else {
+                    syn::parse_quote! { std::default::Default::default() }
+                });
+                Ok(())
+            } else {
+                Err(meta.error("unrecognized structinator field attribute, expected `default`"))
+            }
+        }).expect("The structinator attribute on a field should be valid Rust meta syntax");
+    }
+    default_expr
+}
+
+///Strips every `#[structinator(...)]` attribute off of `item_struct`'s fields, in place.
+///
+/// `structinator` is never registered as a real attribute anywhere in this crate or [`structinator_traits`] — it only exists for
+/// [`field_default_expr`] to read at macro-expansion time. Re-quoting `item_struct` without stripping it would leave the attribute in
+/// the expanded output, which fails to compile with "cannot find attribute `structinator` in this scope".
+fn strip_structinator_attrs(item_struct: &mut syn::ItemStruct) {
+    for field in item_struct.fields.iter_mut() {
+        field.attrs.retain(|attr| !attr.path().is_ident("structinator"));
+    }
+}
+
+#[cfg(test)]
+mod strip_structinator_attrs_tests {
+    use super::*;
+
+    #[test]
+    fn removes_structinator_attrs_but_keeps_others() {
+        let mut item_struct: syn::ItemStruct = syn::parse_str("struct Foo { #[structinator(default = 7)] #[allow(dead_code)] a: u32 }").expect("test struct should parse");
+        strip_structinator_attrs(&mut item_struct);
+        let field = &item_struct.fields.iter().next().expect("struct should have one field");
+        assert!(!field.attrs.iter().any(|attr| attr.path().is_ident("structinator")));
+        assert!(field.attrs.iter().any(|attr| attr.path().is_ident("allow")));
+    }
+}
+
+///Which literal syntax the generated `create_struct` must use to build `#base_structure_name`.
+enum StructShape {
+    Named,
+    Tuple,
+    Unit,
+}
+
+///Normalizes `fields` into a [`StructShape`] and the [`FieldDescriptor`]s the rest of this crate works with, covering named,
+/// tuple, and unit structs uniformly.
+fn describe_fields(fields: &syn::Fields) -> (StructShape, Vec<FieldDescriptor>) {
+    match fields {
+        syn::Fields::Named(named_fields) => {
+            let descriptors = named_fields.named.iter().map(|field| {
+                let ident = field.ident.as_ref().expect("All of the fields in a non-tuple struct should be named").clone();
+                let access = quote! { self.#ident };
+                let default_expr = field_default_expr(field);
+                FieldDescriptor { key: ident.to_string(), binding_ident: ident, access, default_expr, ty: field.ty.clone() }
+            }).collect();
+            (StructShape::Named, descriptors)
+        },
+        syn::Fields::Unnamed(unnamed_fields) => {
+            let descriptors = unnamed_fields.unnamed.iter().enumerate().map(|(index, field)| {
+                let tuple_index = syn::Index::from(index);
+                let access = quote! { self.#tuple_index };
+                let default_expr = field_default_expr(field);
+                FieldDescriptor { key: index.to_string(), binding_ident: format_ident!("field_{}", index), access, default_expr, ty: field.ty.clone() }
+            }).collect();
+            (StructShape::Tuple, descriptors)
+        },
+        syn::Fields::Unit => (StructShape::Unit, Vec::new()),
+    }
+}
+
+#[cfg(test)]
+mod describe_fields_tests {
+    use super::*;
+
+    fn parse_fields(struct_definition: &str) -> syn::Fields {
+        syn::parse_str::<syn::ItemStruct>(struct_definition).expect("test struct should parse").fields
+    }
+
+    #[test]
+    fn named_struct_keys_by_field_name() {
+        let (shape, descriptors) = describe_fields(&parse_fields("struct Foo { a: u32, b: String }"));
+        assert!(matches!(shape, StructShape::Named));
+        assert_eq!(descriptors.iter().map(|d| d.key.clone()).collect::<Vec<_>>(), vec!["a".to_string(), "b".to_string()]);
+    }
+
+    #[test]
+    fn tuple_struct_keys_by_stringified_position() {
+        let (shape, descriptors) = describe_fields(&parse_fields("struct Foo(u32, String);"));
+        assert!(matches!(shape, StructShape::Tuple));
+        assert_eq!(descriptors.iter().map(|d| d.key.clone()).collect::<Vec<_>>(), vec!["0".to_string(), "1".to_string()]);
+        assert_eq!(descriptors[0].binding_ident, format_ident!("field_0"));
+    }
+
+    #[test]
+    fn unit_struct_has_no_fields() {
+        let (shape, descriptors) = describe_fields(&parse_fields("struct Foo;"));
+        assert!(matches!(shape, StructShape::Unit));
+        assert!(descriptors.is_empty());
+    }
+
+    #[test]
+    fn structinator_default_attribute_is_picked_up() {
+        let (_, descriptors) = describe_fields(&parse_fields("struct Foo { #[structinator(default = 7)] a: u32 }"));
+        assert!(descriptors[0].default_expr.is_some());
+    }
+}
+
+///Builds the `#base_structure_name { ... }` / `#base_structure_name( ... )` / `#base_structure_name` literal that matches `shape`,
+/// pairing each descriptor (in declaration order) with its already-computed value expression.
+fn build_struct_literal(base_structure_name: &syn::Ident, shape: &StructShape, descriptors: &[FieldDescriptor], value_exprs: &[proc_macro2::TokenStream]) -> proc_macro2::TokenStream {
+    match shape {
+        StructShape::Named => {
+            let binding_idents = descriptors.iter().map(|descriptor| &descriptor.binding_ident);
+            quote! { #base_structure_name { #(#binding_idents: #value_exprs),* } }
+        },
+        StructShape::Tuple => quote! { #base_structure_name( #(#value_exprs),* ) },
+        StructShape::Unit => quote! { #base_structure_name },
+    }
+}
+
+///Bundles a target struct's [`syn::Generics`], split for use in a trait impl header, together with the extra bounds
+/// [`build_create_struct_impl`] and [`build_into_iterator_impl`] need each of the struct's own type parameters to satisfy.
+///
+/// Built once per attribute invocation by [`build_generics_parts`] and threaded into every generated impl, so `impl_generics`,
+/// `ty_generics`, and `where_clause` always agree with each other.
+struct GenericsParts<'a> {
+    impl_generics: syn::ImplGenerics<'a>,
+    ty_generics: syn::TypeGenerics<'a>,
+    where_clause: proc_macro2::TokenStream,
+}
+
+///Returns whether `ty` *is* `ident`, i.e. whether a field's type is exactly the bare type parameter rather than merely containing it.
+///
+/// The bound [`build_generics_parts`] adds attaches to a field's whole type (`<FieldType as TryFrom<InnerIteratorType>>::try_from(...)`),
+/// so a field typed `Vec<T>` or `PhantomData<U>` doesn't make `T`/`U` themselves need to implement [`TryFrom`] — only a field typed
+/// exactly `T` does. Comparing stringified tokens is a cheap stand-in for real type equality, the same trick [`unique_field_types`] uses.
+fn type_is_bare_type_param(ty: &syn::Type, ident: &syn::Ident) -> bool {
+    *ident == quote! { #ty }.to_string()
+}
+
+///Splits `generics` into the pieces a trait impl header needs, and folds in the bounds a generic field type parameter needs on both
+/// sides of the conversion: `T: TryFrom<#inner_iterator_type>` (plus a `Debug` bound on the associated `Error`, since the generated
+/// `create_struct` calls `.expect()` on the result) for [`build_create_struct_impl`]'s direction, and `#inner_iterator_type: From<T>`
+/// for [`build_into_iterator_impl`]'s reverse direction. Only type parameters that are themselves, bare, one of `descriptors`' field
+/// types get these bounds added; a parameter that's merely used inside a field type (e.g. a `PhantomData<U>`-only parameter) is left alone.
+///
+/// Those extra bounds are what let a generic struct (e.g. `Foo<T>`) satisfy the per-field [`TryFrom`]/[`From`] calls this crate's generated
+/// impls emit, since the compiler can't otherwise assume an abstract `T` converts to and from whatever `InnerIteratorType` is in play.
+fn build_generics_parts<'a>(generics: &'a syn::Generics, inner_iterator_type: &syn::Type, descriptors: &[FieldDescriptor]) -> GenericsParts<'a> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let extra_bounds: Vec<proc_macro2::TokenStream> = generics.type_params().filter(|type_param| {
+        descriptors.iter().any(|descriptor| type_is_bare_type_param(&descriptor.ty, &type_param.ident))
+    }).flat_map(|type_param| {
+        let type_param_ident = &type_param.ident;
+        [
+            quote! { #type_param_ident: std::convert::TryFrom<#inner_iterator_type> },
+            quote! { <#type_param_ident as std::convert::TryFrom<#inner_iterator_type>>::Error: std::fmt::Debug },
+            quote! { #inner_iterator_type: std::convert::From<#type_param_ident> },
+        ]
+    }).collect();
+    let where_clause = if extra_bounds.is_empty() {
+        quote! { #where_clause }
+    } else {
+        match where_clause {
+            Some(existing_clause) => quote! { #existing_clause, #(#extra_bounds),* },
+            None => quote! { where #(#extra_bounds),* },
+        }
+    };
+    GenericsParts { impl_generics, ty_generics, where_clause }
+}
+
+#[cfg(test)]
+mod build_generics_parts_tests {
+    use super::*;
+
+    fn field_of_type(ty: &str) -> FieldDescriptor {
+        FieldDescriptor {
+            key: "value".to_string(),
+            binding_ident: format_ident!("value"),
+            access: proc_macro2::TokenStream::new(),
+            default_expr: None,
+            ty: syn::parse_str(ty).expect("test type should parse"),
+        }
+    }
+
+    #[test]
+    fn adds_both_conversion_bounds_and_a_debug_bound_for_a_used_type_parameter() {
+        let generics: syn::Generics = syn::parse_str("<T>").expect("test generics should parse");
+        let inner_iterator_type: syn::Type = syn::parse_str("MyEnum").expect("test type should parse");
+        let descriptors = vec![field_of_type("T")];
+        let generics_parts = build_generics_parts(&generics, &inner_iterator_type, &descriptors);
+        let where_clause_string: String = generics_parts.where_clause.to_string().chars().filter(|c| !c.is_whitespace()).collect();
+        assert!(where_clause_string.contains("T:std::convert::TryFrom<MyEnum>"), "missing TryFrom bound in {where_clause_string}");
+        assert!(where_clause_string.contains("MyEnum:std::convert::From<T>"), "missing reverse From bound in {where_clause_string}");
+        assert!(where_clause_string.contains("<Tasstd::convert::TryFrom<MyEnum>>::Error:std::fmt::Debug"), "missing Error: Debug bound in {where_clause_string}");
+    }
+
+    #[test]
+    fn no_type_parameters_means_no_added_where_clause() {
+        let generics = syn::Generics::default();
+        let inner_iterator_type: syn::Type = syn::parse_str("MyEnum").expect("test type should parse");
+        let generics_parts = build_generics_parts(&generics, &inner_iterator_type, &[]);
+        assert!(generics_parts.where_clause.is_empty());
+    }
+
+    ///A type parameter that no field actually uses (e.g. a phantom one) should be left without any added bounds.
+    #[test]
+    fn unused_type_parameter_gets_no_bounds() {
+        let generics: syn::Generics = syn::parse_str("<T, U>").expect("test generics should parse");
+        let inner_iterator_type: syn::Type = syn::parse_str("MyEnum").expect("test type should parse");
+        let descriptors = vec![field_of_type("T"), field_of_type("std::marker::PhantomData<U>")];
+        let generics_parts = build_generics_parts(&generics, &inner_iterator_type, &descriptors);
+        let where_clause_string: String = generics_parts.where_clause.to_string().chars().filter(|c| !c.is_whitespace()).collect();
+        assert!(where_clause_string.contains("T:std::convert::TryFrom<MyEnum>"));
+        assert!(!where_clause_string.contains("U:std::convert::TryFrom<MyEnum>"));
+        assert!(!where_clause_string.contains("MyEnum:std::convert::From<U>"));
+    }
+}
+
+///Builds the [`SpecifyCreatableStruct`](structinator_traits::SpecifyCreatableStruct) impl shared by every attribute in this crate.
+///
+/// `inner_iterator_type` is whatever type is ultimately wired in as [`InnerIteratorType`](structinator_traits::SpecifyCreatableStruct::InnerIteratorType),
+/// whether that's a hand-written enum passed to [`macro@iter_convertable`] or one synthesized by [`macro@auto_iter_convertable`].
+fn build_create_struct_impl(inner_iterator_type: &syn::Type, base_structure_name: &syn::Ident, generics_parts: &GenericsParts, shape: &StructShape, descriptors: &[FieldDescriptor], error_handling: ErrorHandling, lookup: ValueLookup) -> proc_macro2::TokenStream {
+    let fields_length = descriptors.len();
+    let GenericsParts { impl_generics, ty_generics, where_clause } = generics_parts;
+    match (lookup, error_handling) {
+        (ValueLookup::HashMap, ErrorHandling::PanicOnFailure) => {
+            let value_exprs: Vec<proc_macro2::TokenStream> = descriptors.iter().map(map_field_value_expr(inner_iterator_type)).collect();
+            let struct_literal = build_struct_literal(base_structure_name, shape, descriptors, &value_exprs);
+            let required_keys = descriptors.iter().filter(|descriptor| descriptor.default_expr.is_none()).map(|descriptor| &descriptor.key);
+            quote! {
+                impl #impl_generics SpecifyCreatableStruct for #base_structure_name #ty_generics #where_clause {
+                    type InnerIteratorType = #inner_iterator_type;
+                    type Error = &'static str;
+                    fn create_struct(seed_iterator: &mut dyn Iterator<Item = NamedField<Self::InnerIteratorType>>) -> Result<Self,&'static str> {
+                        let mut value_storage: std::collections::HashMap<String,Self::InnerIteratorType> = std::collections::HashMap::with_capacity(#fields_length);
+                        let mut looper: usize = 0;
+                        while looper < #fields_length {
+                            match seed_iterator.next() {
+                                Some(next_value_pair) => { value_storage.insert(next_value_pair.name,next_value_pair.wrapped_value); },
+                                None => {
+                                    if false #(|| !value_storage.contains_key(#required_keys))* {
+                                        return Err("The given iterator should contain enough values to fill the implementing structure");
+                                    }
+                                    break
+                                },
+                            }
+                            looper += 1;
+                        }
+                        Ok(#struct_literal)
+                    }
+                }
+            }
+        },
+        (ValueLookup::HashMap, ErrorHandling::AccumulateErrors) => {
+            let field_bindings = descriptors.iter().map(map_accumulating_field_binding(inner_iterator_type));
+            let unwrap_exprs: Vec<proc_macro2::TokenStream> = descriptors.iter().map(|descriptor| {
+                let binding_ident = &descriptor.binding_ident;
+                quote! { #binding_ident.expect("all fields were validated to be present and convertible above") }
+            }).collect();
+            let struct_literal = build_struct_literal(base_structure_name, shape, descriptors, &unwrap_exprs);
+            quote! {
+                impl #impl_generics SpecifyCreatableStruct for #base_structure_name #ty_generics #where_clause {
+                    type InnerIteratorType = #inner_iterator_type;
+                    type Error = Vec<StructError>;
+                    fn create_struct(seed_iterator: &mut dyn Iterator<Item = NamedField<Self::InnerIteratorType>>) -> Result<Self, Vec<StructError>> {
+                        let mut value_storage: std::collections::HashMap<String,Self::InnerIteratorType> = std::collections::HashMap::with_capacity(#fields_length);
+                        let mut looper: usize = 0;
+                        while looper < #fields_length {
+                            match seed_iterator.next() {
+                                Some(next_value_pair) => { value_storage.insert(next_value_pair.name,next_value_pair.wrapped_value); },
+                                None => break,
+                            }
+                            looper += 1;
+                        }
+                        let mut errors: Vec<StructError> = Vec::new();
+                        #(#field_bindings)*
+                        if errors.is_empty() {
+                            Ok(#struct_literal)
+                        } else {
+                            Err(errors)
+                        }
+                    }
+                }
+            }
+        },
+        (ValueLookup::Positional, ErrorHandling::PanicOnFailure) => {
+            let value_exprs: Vec<proc_macro2::TokenStream> = descriptors.iter().map(positional_field_value_expr(inner_iterator_type)).collect();
+            let struct_literal = build_struct_literal(base_structure_name, shape, descriptors, &value_exprs);
+            quote! {
+                impl #impl_generics SpecifyCreatableStruct for #base_structure_name #ty_generics #where_clause {
+                    type InnerIteratorType = #inner_iterator_type;
+                    type Error = &'static str;
+                    fn create_struct(seed_iterator: &mut dyn Iterator<Item = NamedField<Self::InnerIteratorType>>) -> Result<Self,&'static str> {
+                        Ok(#struct_literal)
+                    }
+                }
+            }
+        },
+        (ValueLookup::Positional, ErrorHandling::AccumulateErrors) => {
+            let field_bindings = descriptors.iter().map(positional_accumulating_field_binding(inner_iterator_type));
+            let unwrap_exprs: Vec<proc_macro2::TokenStream> = descriptors.iter().map(|descriptor| {
+                let binding_ident = &descriptor.binding_ident;
+                quote! { #binding_ident.expect("all fields were validated to be present and convertible above") }
+            }).collect();
+            let struct_literal = build_struct_literal(base_structure_name, shape, descriptors, &unwrap_exprs);
+            quote! {
+                impl #impl_generics SpecifyCreatableStruct for #base_structure_name #ty_generics #where_clause {
+                    type InnerIteratorType = #inner_iterator_type;
+                    type Error = Vec<StructError>;
+                    fn create_struct(seed_iterator: &mut dyn Iterator<Item = NamedField<Self::InnerIteratorType>>) -> Result<Self, Vec<StructError>> {
+                        let mut errors: Vec<StructError> = Vec::new();
+                        #(#field_bindings)*
+                        if errors.is_empty() {
+                            Ok(#struct_literal)
+                        } else {
+                            Err(errors)
+                        }
+                    }
+                }
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod build_create_struct_impl_tests {
+    use super::*;
+
+    fn descriptors_for(struct_definition: &str) -> (StructShape, Vec<FieldDescriptor>) {
+        let item_struct: syn::ItemStruct = syn::parse_str(struct_definition).expect("test struct should parse");
+        describe_fields(&item_struct.fields)
+    }
+
+    ///Expands the generated body and strips whitespace, so assertions don't depend on `proc_macro2`'s exact token-spacing rules.
+    fn normalized(tokens: proc_macro2::TokenStream) -> String {
+        tokens.to_string().chars().filter(|c| !c.is_whitespace()).collect()
+    }
 
+    ///Expands `create_struct` for every `(ValueLookup, ErrorHandling)` combination and spot-checks each generated body for the markers
+    /// that distinguish it from the other three: this stands in for a `trybuild`-style expansion check in a crate with no manifest to
+    /// actually compile one against.
+    #[test]
+    fn every_mode_combination_expands_to_its_own_distinct_body() {
+        let inner_iterator_type: syn::Type = syn::parse_str("MyEnum").expect("test type should parse");
+        let generics = syn::Generics::default();
+        let base_structure_name = format_ident!("Foo");
+        let (shape, descriptors) = descriptors_for("struct Foo { a: u32 }");
+        let generics_parts = build_generics_parts(&generics, &inner_iterator_type, &descriptors);
+
+        let hashmap_panic = normalized(build_create_struct_impl(&inner_iterator_type, &base_structure_name, &generics_parts, &shape, &descriptors, ErrorHandling::PanicOnFailure, ValueLookup::HashMap));
+        assert!(hashmap_panic.contains("HashMap"));
+        assert!(hashmap_panic.contains("value_storage"));
+        assert!(!hashmap_panic.contains("StructError"));
+
+        let hashmap_accumulate = normalized(build_create_struct_impl(&inner_iterator_type, &base_structure_name, &generics_parts, &shape, &descriptors, ErrorHandling::AccumulateErrors, ValueLookup::HashMap));
+        assert!(hashmap_accumulate.contains("HashMap"));
+        assert!(hashmap_accumulate.contains("Vec<StructError>"));
+
+        let positional_panic = normalized(build_create_struct_impl(&inner_iterator_type, &base_structure_name, &generics_parts, &shape, &descriptors, ErrorHandling::PanicOnFailure, ValueLookup::Positional));
+        assert!(!positional_panic.contains("HashMap"));
+        assert!(positional_panic.contains("seed_iterator.next"));
+        assert!(!positional_panic.contains("StructError"));
+
+        let positional_accumulate = normalized(build_create_struct_impl(&inner_iterator_type, &base_structure_name, &generics_parts, &shape, &descriptors, ErrorHandling::AccumulateErrors, ValueLookup::Positional));
+        assert!(!positional_accumulate.contains("HashMap"));
+        assert!(positional_accumulate.contains("seed_iterator.next"));
+        assert!(positional_accumulate.contains("Vec<StructError>"));
+    }
+
+    #[test]
+    fn unit_struct_create_struct_ignores_the_iterator() {
+        let inner_iterator_type: syn::Type = syn::parse_str("MyEnum").expect("test type should parse");
+        let generics = syn::Generics::default();
+        let base_structure_name = format_ident!("Foo");
+        let (shape, descriptors) = descriptors_for("struct Foo;");
+        let generics_parts = build_generics_parts(&generics, &inner_iterator_type, &descriptors);
+        let expanded = normalized(build_create_struct_impl(&inner_iterator_type, &base_structure_name, &generics_parts, &shape, &descriptors, ErrorHandling::PanicOnFailure, ValueLookup::HashMap));
+        assert!(expanded.contains("Ok(Foo)"));
+    }
+}
+
+///Builds the per-field value expression for [`ValueLookup::HashMap`] under [`ErrorHandling::PanicOnFailure`]: look the field's value up
+/// in `value_storage` by `key`, then [`TryFrom`] it into the field's type, panicking on a failed conversion. If the field carries a
+/// `#[structinator(default)]` expression, a missing value falls back to it instead of panicking.
+fn map_field_value_expr(inner_iterator_type: &syn::Type) -> impl Fn(&FieldDescriptor) -> proc_macro2::TokenStream + '_ {
+    move |descriptor: &FieldDescriptor| -> proc_macro2::TokenStream {
+        let field_type = &descriptor.ty;
+        let key = &descriptor.key;
+        match &descriptor.default_expr {
+            Some(default_expr) => quote! {
+                value_storage.remove(#key).map_or_else(
+                    || #default_expr,
+                    |raw_value| <#field_type as std::convert::TryFrom<#inner_iterator_type>>::try_from(raw_value).expect("The variant of InnerIteratorType passed to TryFrom should always succeed in conversion, but it failed unexpectedly"),
+                )
+            },
+            None => quote! {
+                <#field_type as std::convert::TryFrom<#inner_iterator_type>>::try_from(value_storage.remove(#key).expect("The iterator passed to the create_struct function should yield values for every field in the base struct")).expect("The variant of InnerIteratorType passed to TryFrom should always succeed in conversion, but it failed unexpectedly")
+            },
+        }
+    }
+}
+
+///Builds the per-field local binding [`ValueLookup::HashMap`] uses under [`ErrorHandling::AccumulateErrors`]: a
+/// `let #binding_ident: Option<FieldType>` that is `Some` when the field was both present in `value_storage` and convertible, and `None`
+/// (after pushing a [`StructError`](structinator_traits::StructError) onto `errors`) otherwise. If the field carries a
+/// `#[structinator(default)]` expression, a missing value falls back to it instead of being recorded as an error.
+fn map_accumulating_field_binding(inner_iterator_type: &syn::Type) -> impl Fn(&FieldDescriptor) -> proc_macro2::TokenStream + '_ {
+    move |descriptor: &FieldDescriptor| -> proc_macro2::TokenStream {
+        let binding_ident = &descriptor.binding_ident;
+        let field_type = &descriptor.ty;
+        let key = &descriptor.key;
+        let missing_case = match &descriptor.default_expr {
+            Some(default_expr) => quote! { Some(#default_expr) },
+            None => quote! {
+                {
+                    errors.push(StructError::MissingField(#key));
+                    None
+                }
+            },
+        };
+        quote! {
+            let #binding_ident: Option<#field_type> = match value_storage.remove(#key) {
+                Some(raw_value) => match <#field_type as std::convert::TryFrom<#inner_iterator_type>>::try_from(raw_value) {
+                    Ok(converted) => Some(converted),
+                    Err(_) => {
+                        errors.push(StructError::ConversionFailed(#key));
+                        None
+                    },
+                },
+                None => #missing_case,
+            };
+        }
+    }
+}
+
+///Builds the per-field value expression for [`ValueLookup::Positional`] under [`ErrorHandling::PanicOnFailure`]: pull the next value
+/// straight off `seed_iterator`, without ever allocating or hashing into a [`HashMap`](std::collections::HashMap). The `name` on the
+/// yielded [`NamedField`](structinator_traits::NamedField) is only checked by a [`debug_assert_eq!`], since positional mode trusts the
+/// caller to yield values in field-declaration order. If the field carries a `#[structinator(default)]` expression, an exhausted
+/// iterator falls back to it instead of returning an error.
+fn positional_field_value_expr(inner_iterator_type: &syn::Type) -> impl Fn(&FieldDescriptor) -> proc_macro2::TokenStream + '_ {
+    move |descriptor: &FieldDescriptor| -> proc_macro2::TokenStream {
+        let field_type = &descriptor.ty;
+        let key = &descriptor.key;
+        match &descriptor.default_expr {
+            Some(default_expr) => quote! {
+                match seed_iterator.next() {
+                    Some(next_value_pair) => {
+                        debug_assert_eq!(next_value_pair.name, #key, "The iterator passed to the create_struct function should yield values in field-declaration order when the positional option is used");
+                        <#field_type as std::convert::TryFrom<#inner_iterator_type>>::try_from(next_value_pair.wrapped_value).expect("The variant of InnerIteratorType passed to TryFrom should always succeed in conversion, but it failed unexpectedly")
+                    },
+                    None => #default_expr,
+                }
+            },
+            None => quote! {
+                {
+                    let next_value_pair = seed_iterator.next().ok_or("The given iterator should contain enough values to fill the implementing structure")?;
+                    debug_assert_eq!(next_value_pair.name, #key, "The iterator passed to the create_struct function should yield values in field-declaration order when the positional option is used");
+                    <#field_type as std::convert::TryFrom<#inner_iterator_type>>::try_from(next_value_pair.wrapped_value).expect("The variant of InnerIteratorType passed to TryFrom should always succeed in conversion, but it failed unexpectedly")
+                }
+            },
+        }
+    }
+}
+
+///Builds the per-field local binding [`ValueLookup::Positional`] uses under [`ErrorHandling::AccumulateErrors`]: like
+/// [`map_accumulating_field_binding`], but pulling straight from `seed_iterator` instead of `value_storage`.
+fn positional_accumulating_field_binding(inner_iterator_type: &syn::Type) -> impl Fn(&FieldDescriptor) -> proc_macro2::TokenStream + '_ {
+    move |descriptor: &FieldDescriptor| -> proc_macro2::TokenStream {
+        let binding_ident = &descriptor.binding_ident;
+        let field_type = &descriptor.ty;
+        let key = &descriptor.key;
+        let missing_case = match &descriptor.default_expr {
+            Some(default_expr) => quote! { Some(#default_expr) },
+            None => quote! {
+                {
+                    errors.push(StructError::MissingField(#key));
+                    None
+                }
+            },
+        };
+        quote! {
+            let #binding_ident: Option<#field_type> = match seed_iterator.next() {
+                Some(next_value_pair) => match <#field_type as std::convert::TryFrom<#inner_iterator_type>>::try_from(next_value_pair.wrapped_value) {
+                    Ok(converted) => Some(converted),
+                    Err(_) => {
+                        errors.push(StructError::ConversionFailed(#key));
+                        None
+                    },
+                },
+                None => #missing_case,
+            };
+        }
+    }
+}
+
+///Builds the reverse of [`build_create_struct_impl`]: an [`IntoIterator`] impl that turns `#base_structure_name` back into a fixed-size
+/// iterator of [`NamedField`](structinator_traits::NamedField)s, one per field, in field-declaration order.
+///
+/// This is what makes the transformation round-trippable: feeding a struct's [`IntoIterator`] straight into another
+/// [`create_struct`](structinator_traits::SpecifyCreatableStruct::create_struct) call reconstructs it (or seeds a different struct with the same field types).
+fn build_into_iterator_impl(inner_iterator_type: &syn::Type, base_structure_name: &syn::Ident, generics_parts: &GenericsParts, descriptors: &[FieldDescriptor]) -> proc_macro2::TokenStream {
+    let fields_length = descriptors.len();
+    let GenericsParts { impl_generics, ty_generics, where_clause } = generics_parts;
+    let field_entries = descriptors.iter().map(|descriptor| {
+        let key = &descriptor.key;
+        let field_type = &descriptor.ty;
+        let access = &descriptor.access;
+        quote! {
+            NamedField { name: #key.to_string(), wrapped_value: <#inner_iterator_type as std::convert::From<#field_type>>::from(#access) }
+        }
+    });
+    quote! {
+        impl #impl_generics std::iter::IntoIterator for #base_structure_name #ty_generics #where_clause {
+            type Item = NamedField<#inner_iterator_type>;
+            type IntoIter = std::array::IntoIter<NamedField<#inner_iterator_type>, #fields_length>;
+            fn into_iter(self) -> Self::IntoIter {
+                [#(#field_entries),*].into_iter()
+            }
+        }
+    }
+}
+
+///Collects the set of *unique* field types out of `descriptors`, preserving first-seen order.
+///
+/// Two fields are considered to share a type if their [`syn::Type`]s stringify to the same token sequence; this is a cheap
+/// stand-in for proper type equality, since [`syn::Type`] does not implement [`PartialEq`] without the `extra-traits` feature.
+fn unique_field_types(descriptors: &[FieldDescriptor]) -> Vec<syn::Type> {
+    let mut seen_type_strings = std::collections::HashSet::new();
+    let mut unique_types = Vec::new();
+    for descriptor in descriptors {
+        let field_type = &descriptor.ty;
+        let type_string = quote! { #field_type }.to_string();
+        if seen_type_strings.insert(type_string) {
+            unique_types.push(descriptor.ty.clone());
+        }
+    }
+    unique_types
+}
+
+#[cfg(test)]
+mod unique_field_types_tests {
+    use super::*;
+
+    fn field(key: &str, ty: &str) -> FieldDescriptor {
+        FieldDescriptor {
+            key: key.to_string(),
+            binding_ident: format_ident!("{}", key),
+            access: proc_macro2::TokenStream::new(),
+            default_expr: None,
+            ty: syn::parse_str(ty).expect("test type should parse"),
+        }
+    }
+
+    #[test]
+    fn collects_every_distinct_type_once() {
+        let descriptors = vec![field("a", "u32"), field("b", "String"), field("c", "bool"), field("d", "u32")];
+        let unique_type_strings: Vec<String> = unique_field_types(&descriptors).iter().map(|ty| quote! { #ty }.to_string()).collect();
+        assert_eq!(unique_type_strings, vec!["u32".to_string(), "String".to_string(), "bool".to_string()]);
+    }
+
+    #[test]
+    fn repeated_type_collapses_to_a_single_entry() {
+        let descriptors = vec![field("a", "u32"), field("b", "u32")];
+        assert_eq!(unique_field_types(&descriptors).len(), 1);
+    }
+}
+
+///Synthesizes a private enum with one variant per entry in `unique_types`, along with the [`From`]/[`TryFrom`] impls
+/// [`build_create_struct_impl`] needs to treat it as an [`InnerIteratorType`](structinator_traits::SpecifyCreatableStruct::InnerIteratorType).
+///
+/// Returns the [`syn::Type`] referring to the new enum, paired with the token stream that defines the enum and its impls.
+fn build_auto_enum(base_structure_name: &syn::Ident, unique_types: &[syn::Type]) -> (syn::Type, proc_macro2::TokenStream) {
+    let enum_name = format_ident!("__{}InnerIteratorType", base_structure_name);
+    let variant_names: Vec<syn::Ident> = (0..unique_types.len()).map(|index| format_ident!("Variant{}", index)).collect();
+    let conversion_impls = variant_names.iter().zip(unique_types.iter()).map(|(variant_name, field_type)| {
+        quote! {
+            impl std::convert::From<#field_type> for #enum_name {
+                fn from(value: #field_type) -> Self {
+                    #enum_name::#variant_name(value)
+                }
+            }
+            impl std::convert::TryFrom<#enum_name> for #field_type {
+                type Error = &'static str;
+                fn try_from(value: #enum_name) -> Result<Self, Self::Error> {
+                    match value {
+                        #enum_name::#variant_name(inner) => Ok(inner),
+                        #[allow(unreachable_patterns)]
+                        _ => Err("The variant of the auto-generated InnerIteratorType did not match the expected field type"),
+                    }
+                }
+            }
+        }
+    });
+    let enum_definition = quote! {
+        #[allow(non_camel_case_types)]
+        enum #enum_name {
+            #(#variant_names(#unique_types)),*
+        }
+        #(#conversion_impls)*
+    };
+    let enum_type: syn::Type = syn::parse2(quote! { #enum_name }).expect("The auto-generated enum identifier should always parse as a type");
+    (enum_type, enum_definition)
+}
 
 ///Attribute for structs that can be built from an iterator.
 /// This attribute must be attached to a [`struct`](https://doc.rust-lang.org/1.58.1/std/keyword.struct.html) definition
-/// 
+///
 /// # Argument
-/// 
-///The argument passed to the attribute must be a type, and each unique type of the fields in the target [`struct`](https://doc.rust-lang.org/1.58.1/std/keyword.struct.html) must implement 
+///
+///The argument passed to the attribute must be a type, and each unique type of the fields in the target [`struct`](https://doc.rust-lang.org/1.58.1/std/keyword.struct.html) must implement
 ///[`From`] or [`TryFrom`] the passed type
-/// 
+///
+/// The type may optionally be followed by a comma-separated list of mode options, e.g. `#[iter_convertable(MyEnum, accumulate_errors, positional)]`.
+/// Recognised options are:
+/// - `accumulate_errors`, which switches `create_struct` to the error-accumulating behaviour described under [Errors](#errors) below.
+/// - `positional`, which assumes the supplied iterator yields values in field-declaration order and skips the per-field
+///   [`HashMap`](std::collections::HashMap) lookup entirely, pulling each value straight off the iterator instead. The `name` on each
+///   yielded [`NamedField`](structinator_traits::NamedField) is then not used for lookup; without `accumulate_errors` it is only checked
+///   by a debug assertion, and with `accumulate_errors` it is not checked at all.
+///
 /// # Effects
 /// This attribute implements the trait [`structinator_traits::SpecifyCreatableStruct`] with [`InnerIteratorType`](structinator_traits::SpecifyCreatableStruct::InnerIteratorType) set to
-/// the argument passed to this attribute. 
-/// 
+/// the argument passed to this attribute.
+///
 /// The generated function,  [`create_struct`](structinator_traits::SpecifyCreatableStruct::create_struct), will be implemented using a [`HashMap<String,InnerIteratorType>`](std::collections::HashMap), which will store the first `N` values from the iterator, where `N` is the number of fields in the [`struct`](https://doc.rust-lang.org/1.58.1/std/keyword.struct.html) this attribute is attached to,
-/// and then be assign the values in that [`HashMap`](std::collections::HashMap) to corresponding fields in the [`struct`](https://doc.rust-lang.org/1.58.1/std/keyword.struct.html), as determined by a stringification of the field's name. 
-/// 
+/// and then be assign the values in that [`HashMap`](std::collections::HashMap) to corresponding fields in the [`struct`](https://doc.rust-lang.org/1.58.1/std/keyword.struct.html), as determined by a stringification of the field's name (or, for a tuple struct, its position).
+///
 /// The passed value will then be unwrapped from the [`InnerIteratorType`](structinator_traits::SpecifyCreatableStruct::InnerIteratorType) to the type of the struct, [`panic`](https://doc.rust-lang.org/1.58.1/core/macro.panic.html)king if the conversion fails.
-/// 
+///
+/// This attribute also implements [`IntoIterator`] for the [`struct`](https://doc.rust-lang.org/1.58.1/std/keyword.struct.html), yielding a
+/// [`NamedField`](structinator_traits::NamedField) per field, in field-declaration order, with its value converted back into
+/// [`InnerIteratorType`](structinator_traits::SpecifyCreatableStruct::InnerIteratorType) via [`From`]. This makes the conversion round-trippable:
+/// feeding a struct's iterator straight into another [`create_struct`](structinator_traits::SpecifyCreatableStruct::create_struct) call works as expected.
+///
+/// Named, tuple, and unit structs are all supported. For a tuple struct, each field's `name` is the stringified index of its position
+/// (`"0"`, `"1"`, ...). A unit struct has no fields to convert, so its generated `create_struct` ignores the iterator entirely.
+///
+/// Generic target structs are supported: the struct's own `impl`/type generics and where-clause are carried over onto the generated
+/// [`SpecifyCreatableStruct`](structinator_traits::SpecifyCreatableStruct) and [`IntoIterator`] impls, and each of its type parameters
+/// that is itself the whole type of a field gets an added `TryFrom<InnerIteratorType>` bound (for [`create_struct`](structinator_traits::SpecifyCreatableStruct::create_struct))
+/// and `InnerIteratorType: From<T>` bound (for the generated [`IntoIterator`] impl), so a field whose type is one of those parameters
+/// (e.g. `value: T` on `Foo<T>`) still type-checks in both directions. A type parameter only used inside a field's type (e.g. in
+/// `PhantomData<U>`) gets no added bounds.
+///
+/// An individual field can be made optional by attaching `#[structinator(default)]` or `#[structinator(default = expr)]` to it. When the
+/// collected values have nothing for that field, `create_struct` falls back to [`Default::default()`](Default::default) or the given
+/// `expr` instead of treating it as a missing field (whether that's a [`panic`](https://doc.rust-lang.org/1.58.1/core/macro.panic.html)
+/// or an entry in [`Vec<StructError>`](structinator_traits::StructError) under `accumulate_errors`).
+///
 /// In other words, if the field definition looks like this:
 /// ```no_run
 /// value_name: u16,
@@ -57,71 +756,107 @@ use proc_macro::TokenStream;
 /// ```
 /// # Panics
 /// This attribute will cause a [`panic`](https://doc.rust-lang.org/1.58.1/core/macro.panic.html) if attached to anything other than a [`struct`](https://doc.rust-lang.org/1.58.1/std/keyword.struct.html) definition
-/// 
+///
 /// This attribute will implement [`SpecifyCreatableStruct`](structinator_traits::SpecifyCreatableStruct) in a manner that assumes the [`InnerIteratorType`](structinator_traits::SpecifyCreatableStruct::InnerIteratorType)
 /// implements [`TryFrom`] for each unique type used in the fields of the target [`struct`](https://doc.rust-lang.org/1.58.1/std/keyword.struct.html)
-/// 
+///
 /// If [`InnerIteratorType`](structinator_traits::SpecifyCreatableStruct::InnerIteratorType)'s type does not implement [`TryFrom`], or the conversion fails, this function will [`panic`](https://doc.rust-lang.org/1.58.1/core/macro.panic.html).
 /// The recomended way to make sure [`TryFrom`] is always implemented, minimizing panics to only when the conversion itself fails, is to create an [`enum`](https://doc.rust-lang.org/1.58.1/std/keyword.enum.html) specifically for this purpose, with unique variants for each unique type used by the fields of the target [`struct`](https://doc.rust-lang.org/1.58.1/std/keyword.struct.html),
 /// and add the attribute [`unique_try_froms()`](https://docs.rs/enum_unwrapper/0.1.2/enum_unwrapper/attr.unique_try_froms.html) to said [`enum`](https://doc.rust-lang.org/1.58.1/std/keyword.enum.html).
 /// See [`enum_unwrapper`](https://docs.rs/enum_unwrapper/0.1.2/enum_unwrapper/index.html)'s documentation for detailed
 /// instructions on how to do so.
-/// 
+///
 /// The function will also [`panic`](https://doc.rust-lang.org/1.58.1/core/macro.panic.html) if the [`Iterator`] argument yields [`NamedField`](structinator_traits::NamedField)s with identical [`name`](structinator_traits::NamedField::name) values before providing enough values to fill the target [`struct`](https://doc.rust-lang.org/1.58.1/std/keyword.struct.html).
-/// 
-/// # Errors 
-/// The generated implementation returns an [`Err`] containing a [`&'static str`](str) if the supplied [`Iterator`] returns [`None`] before yielding enough values to fill the target [`struct`](https://doc.rust-lang.org/1.58.1/std/keyword.struct.html).
+///
+/// # Errors
+/// Without the `accumulate_errors` option, the generated implementation returns an [`Err`] containing a [`&'static str`](str) if the supplied
+/// [`Iterator`] returns [`None`] before yielding a value for a field that has no `#[structinator(default...)]` fallback. Fields carrying
+/// `#[structinator(default)]`/`#[structinator(default = expr)]` fall back to their default instead.
+///
+/// If the `accumulate_errors` option is passed, `create_struct` never panics on a conversion failure. Instead, [`Error`](structinator_traits::SpecifyCreatableStruct::Error) becomes
+/// [`Vec<StructError>`](structinator_traits::StructError), and every missing or unconvertible field is recorded as its own entry rather than stopping at the first one;
+/// the struct literal is only built once every field has been confirmed present and convertible.
 /// # More Info
 /// See [`SpecifyCreatableStruct`](structinator_traits::SpecifyCreatableStruct) documentation for more information & examples.
-/// 
+///
 #[proc_macro_attribute]
 pub fn iter_convertable(user_enum: TokenStream, user_structure: TokenStream) -> TokenStream {
-    let inner_iterator_type = if let Ok(specific_enum) = syn::parse::<syn::Type>(user_enum) {
-        specific_enum
+    let parsed_args: IterConvertableArgs = syn::parse(user_enum).expect("Pass in the name of the enum that contains the values to be assigned to this structure, optionally followed by a comma-separated list of mode options.");
+    let inner_iterator_type = parsed_args.inner_iterator_type;
+    let error_handling = if parsed_args.options.contains("accumulate_errors") {
+        ErrorHandling::AccumulateErrors
+    } else {
+        ErrorHandling::PanicOnFailure
+    };
+    let lookup = if parsed_args.options.contains("positional") {
+        ValueLookup::Positional
     } else {
-        panic!("Pass in the name of the enum that contains the values to be assigned to this structure.");
+        ValueLookup::HashMap
     };
-    let base_structure: syn::ItemStruct = syn::parse(user_structure).expect("This attribute should only be attached to a struct definition");
-    //to do: alter the value to ensure generics work
+    let mut base_structure: syn::ItemStruct = syn::parse(user_structure).expect("This attribute should only be attached to a struct definition");
+    let (shape, descriptors) = describe_fields(&base_structure.fields);
+    strip_structinator_attrs(&mut base_structure);
     let base_structure_borrow = &base_structure;
     let base_structure_name = &base_structure.ident;
-    let fields = match base_structure.fields {
-        syn::Fields::Named(ref field_list) => &field_list.named,
-        //note to self: add this part when tuple_structinator is live
-        //syn::Fields::Unnamed(_) => panic!("This library only converts from iterators to structs with named fields. consider using this library's sister library, tuple_structinator, instead"),
-        _ => panic!("This library can only convert from iterator to structs with named fields"),
-    };
-    let fields_length = fields.len();
-    let field_maker = |field: &syn::Field| -> syn::FieldValue {
-        let name_copy = field.ident.as_ref().expect("All of the fields in a non-tuple struct should be named").clone();
-        let name_string = name_copy.to_string();
-        let field_type = field.ty.clone();
-        syn::parse2(quote! {
-            #name_copy: <#field_type as std::convert::TryFrom<#inner_iterator_type>>::try_from(value_storage.remove(#name_string).expect("The iterator passed to the create_struct function should yield values for every field in the base struct")).expect("The variant of InnerIteratorType passed to TryFrom should always succeed in conversion, but it failed unexpectedly")
-        }).expect("An unexpected error occured. If the error persists, consider using simpler types with fewer generics")
-    };
-    let fields_iterator = fields.iter().map(field_maker);
+    let generics_parts = build_generics_parts(&base_structure.generics, &inner_iterator_type, &descriptors);
+    let impl_block = build_create_struct_impl(&inner_iterator_type, base_structure_name, &generics_parts, &shape, &descriptors, error_handling, lookup);
+    let into_iterator_impl = build_into_iterator_impl(&inner_iterator_type, base_structure_name, &generics_parts, &descriptors);
     return quote! {
         #base_structure_borrow
-        impl SpecifyCreatableStruct for #base_structure_name {
-            type InnerIteratorType = #inner_iterator_type;
-            type Error = &'static str;
-            fn create_struct(seed_iterator: &mut dyn Iterator<Item = NamedField<Self::InnerIteratorType>>) -> Result<Self,&'static str> {
-                let mut value_storage: std::collections::HashMap<String,Self::InnerIteratorType> = std::collections::HashMap::with_capacity(#fields_length);
-                let mut looper: usize = 0;
-                while looper < #fields_length {
-                    let mut next_value_pair = if let Some(next) = seed_iterator.next() {
-                        next
-                    } else {
-                        return Err("The given iterator should contain enough values to fill the implementing structure");
-                    };
-                    value_storage.insert(next_value_pair.name,next_value_pair.wrapped_value);
-                    looper += 1;
-                }
-                Ok(#base_structure_name {
-                    #(#fields_iterator),*
-                })
-            }
-        }       
+        #impl_block
+        #into_iterator_impl
+    }.into()
+}
+
+///Attribute for structs that can be built from an iterator, without having to hand-write the enum [`macro@iter_convertable`] requires.
+/// This attribute must be attached to a [`struct`](https://doc.rust-lang.org/1.58.1/std/keyword.struct.html) definition, and takes no argument.
+///
+/// # Effects
+/// This attribute inspects the fields of the target [`struct`](https://doc.rust-lang.org/1.58.1/std/keyword.struct.html), collects the set of
+/// *unique* field types, and synthesizes a private [`enum`](https://doc.rust-lang.org/1.58.1/std/keyword.enum.html) with one variant per unique type,
+/// complete with [`From`] and [`TryFrom`] impls between each field type and the new [`enum`](https://doc.rust-lang.org/1.58.1/std/keyword.enum.html).
+/// That generated [`enum`](https://doc.rust-lang.org/1.58.1/std/keyword.enum.html) is then wired in as [`InnerIteratorType`](structinator_traits::SpecifyCreatableStruct::InnerIteratorType),
+/// and [`create_struct`](structinator_traits::SpecifyCreatableStruct::create_struct) is implemented exactly as it would be by [`macro@iter_convertable`].
+///
+/// In other words, this attribute does the work [`unique_try_froms()`](https://docs.rs/enum_unwrapper/0.1.2/enum_unwrapper/attr.unique_try_froms.html) and
+/// the hand-written enum would otherwise require, so [`create_struct`](structinator_traits::SpecifyCreatableStruct::create_struct) just works.
+///
+/// Like [`macro@iter_convertable`], this attribute also implements [`IntoIterator`] for the [`struct`](https://doc.rust-lang.org/1.58.1/std/keyword.struct.html),
+/// so the generated conversion is round-trippable through the auto-generated enum as well, and supports named, tuple, and unit structs.
+/// Per-field `#[structinator(default)]` / `#[structinator(default = expr)]` attributes are honoured the same way they are by [`macro@iter_convertable`].
+///
+/// Generic target structs carry their generics onto the generated impls, plus an added `TryFrom<InnerIteratorType>` / `InnerIteratorType: From<T>`
+/// bound pair per type parameter that is itself the whole type of a field, same as [`macro@iter_convertable`]. The auto-generated enum itself is not made generic,
+/// though, so it can only hold a field's *concrete*
+/// type; a field whose type is one of the struct's own type parameters (e.g. `value: T` on `Foo<T>`) needs a hand-written enum via
+/// [`macro@iter_convertable`] instead.
+/// # Panics
+/// This attribute will cause a [`panic`](https://doc.rust-lang.org/1.58.1/core/macro.panic.html) if attached to anything other than a
+/// [`struct`](https://doc.rust-lang.org/1.58.1/std/keyword.struct.html) definition.
+/// # Errors
+/// The generated implementation's [`Error`](structinator_traits::SpecifyCreatableStruct::Error) type is [`&'static str`](str), matching
+/// [`macro@iter_convertable`]'s default mode: an [`Err`] is returned if the supplied [`Iterator`] returns [`None`] before yielding a value
+/// for a field that has no `#[structinator(default...)]` fallback. Fields carrying `#[structinator(default)]`/`#[structinator(default = expr)]`
+/// fall back to their default instead.
+/// # More Info
+/// See [`SpecifyCreatableStruct`](structinator_traits::SpecifyCreatableStruct) documentation for more information & examples.
+///
+#[proc_macro_attribute]
+pub fn auto_iter_convertable(_attribute_args: TokenStream, user_structure: TokenStream) -> TokenStream {
+    let mut base_structure: syn::ItemStruct = syn::parse(user_structure).expect("This attribute should only be attached to a struct definition");
+    let (shape, descriptors) = describe_fields(&base_structure.fields);
+    strip_structinator_attrs(&mut base_structure);
+    let base_structure_borrow = &base_structure;
+    let base_structure_name = &base_structure.ident;
+    let unique_types = unique_field_types(&descriptors);
+    let (inner_iterator_type, enum_definition) = build_auto_enum(base_structure_name, &unique_types);
+    let generics_parts = build_generics_parts(&base_structure.generics, &inner_iterator_type, &descriptors);
+    let impl_block = build_create_struct_impl(&inner_iterator_type, base_structure_name, &generics_parts, &shape, &descriptors, ErrorHandling::PanicOnFailure, ValueLookup::HashMap);
+    let into_iterator_impl = build_into_iterator_impl(&inner_iterator_type, base_structure_name, &generics_parts, &descriptors);
+    return quote! {
+        #base_structure_borrow
+        #enum_definition
+        #impl_block
+        #into_iterator_impl
     }.into()
-}
\ No newline at end of file
+}